@@ -520,14 +520,33 @@ fn resolve_trait_associated_const<'a, 'tcx>(tcx: TyCtxt<'a, 'tcx, 'tcx>,
         // constant should resolve.
         match selection {
             traits::VtableImpl(ref impl_data) => {
+                // Selection only unifies the impl header with the obligation,
+                // so an impl parameter constrained solely by a where-clause
+                // projection (e.g. `U` in `impl<T, U> Trait for T where
+                // T: Iterator<Item=U>`) is still an inference variable here.
+                // Drain the nested obligations, as `trans_fulfill_obligation`
+                // does, to bind it; this also gives up if the impl's
+                // where-clauses turn out not to hold.
+                let mut fulfill_cx = traits::FulfillmentContext::new();
+                for obligation in impl_data.nested.iter().cloned() {
+                    fulfill_cx.register_predicate_obligation(&infcx, obligation);
+                }
+                if fulfill_cx.select_all_or_error(&infcx).is_err() {
+                    return None;
+                }
+                let impl_substs = infcx.resolve_type_vars_if_possible(&impl_data.substs);
+                let impl_substs = infcx.tcx.erase_regions(&impl_substs);
+                let impl_substs = match infcx.tcx.lift_to_global(&impl_substs) {
+                    Some(impl_substs) => impl_substs,
+                    None => return None,
+                };
+
                 let name = trait_item.name;
                 let ac = tcx.associated_items(impl_data.impl_def_id)
                     .find(|item| item.kind == ty::AssociatedKind::Const && item.name == name);
                 match ac {
-                    // FIXME(eddyb) Use proper Instance resolution to
-                    // get the correct Substs returned from here.
                     Some(ic) => {
-                        let substs = Substs::identity_for_item(tcx, ic.def_id);
+                        let substs = substs.rebase_onto(tcx, trait_id, impl_substs);
                         Some((ic.def_id, substs))
                     }
                     None => {
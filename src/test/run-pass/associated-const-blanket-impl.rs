@@ -0,0 +1,65 @@
+// Copyright 2017 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// Check that an associated const provided by a blanket impl is evaluated
+// with the impl's type parameters bound to concrete types, including one
+// that is only constrained by a where-clause projection.
+
+trait Bound {
+    const VAL: usize;
+}
+
+trait Trait {
+    const DOUBLE: usize;
+}
+
+impl<T: Bound> Trait for T {
+    const DOUBLE: usize = T::VAL * 2;
+}
+
+struct Concrete;
+
+impl Bound for Concrete {
+    const VAL: usize = 21;
+}
+
+trait Element {
+    const ELEM: usize;
+}
+
+impl<T, U> Element for T where T: Iterator<Item=U>, U: Bound {
+    const ELEM: usize = U::VAL;
+}
+
+struct Counter;
+
+impl Iterator for Counter {
+    type Item = Concrete;
+    fn next(&mut self) -> Option<Concrete> { None }
+}
+
+const DOUBLE: usize = <Concrete as Trait>::DOUBLE;
+const ELEM: usize = <Counter as Element>::ELEM;
+
+fn main() {
+    assert_eq!(42, DOUBLE);
+
+    match 42 {
+        <Concrete as Trait>::DOUBLE => {},
+        _ => assert!(false)
+    }
+
+    assert_eq!(21, ELEM);
+
+    match 21 {
+        <Counter as Element>::ELEM => {},
+        _ => assert!(false)
+    }
+}
@@ -0,0 +1,50 @@
+// Copyright 2017 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// Check that associated consts whose impl body is a block are found and
+// evaluated in full, both directly and through a blanket impl.
+
+trait Square {
+    const N: usize;
+}
+
+struct Three;
+
+impl Square for Three {
+    const N: usize = {
+        const X: usize = 3;
+        X * X
+    };
+}
+
+trait Offset {
+    const M: usize;
+}
+
+impl<T: Square> Offset for T {
+    const M: usize = {
+        const ONE: usize = 1;
+        T::N + ONE
+    };
+}
+
+const N: usize = <Three as Square>::N;
+const M: usize = <Three as Offset>::M;
+
+fn main() {
+    assert_eq!(9, N);
+    assert_eq!(10, M);
+    assert_eq!(9, Three::N);
+
+    match 9 {
+        <Three as Square>::N => {},
+        _ => assert!(false)
+    }
+}
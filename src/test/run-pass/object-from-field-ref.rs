@@ -0,0 +1,53 @@
+// Copyright 2017 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// Check that coercing a reference to a field into a trait object uses the
+// field's type for the vtable and points the data half at the field.
+
+use std::mem;
+
+trait Get {
+    fn get(&self) -> u64;
+}
+
+impl Get for u16 {
+    fn get(&self) -> u64 { *self as u64 }
+}
+
+impl Get for [u64; 3] {
+    fn get(&self) -> u64 { self.iter().sum() }
+}
+
+struct Outer {
+    a: u8,
+    small: u16,
+    big: [u64; 3],
+}
+
+fn main() {
+    let outer = Outer { a: 1, small: 7, big: [1, 2, 3] };
+
+    let small: &Get = &outer.small;
+    assert_eq!(small.get(), 7);
+    assert_eq!(mem::size_of_val(small), mem::size_of::<u16>());
+    assert_eq!(mem::align_of_val(small), mem::align_of::<u16>());
+    assert_eq!(small as *const Get as *const u8,
+               &outer.small as *const u16 as *const u8);
+
+    let big: &Get = &outer.big;
+    assert_eq!(big.get(), 6);
+    assert_eq!(mem::size_of_val(big), mem::size_of::<[u64; 3]>());
+    assert_eq!(mem::align_of_val(big), mem::align_of::<[u64; 3]>());
+    assert_eq!(big as *const Get as *const u8,
+               &outer.big as *const [u64; 3] as *const u8);
+
+    assert!(mem::size_of_val(big) != mem::size_of::<Outer>());
+    assert_eq!(outer.a, 1);
+}
@@ -0,0 +1,45 @@
+// Copyright 2017 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// Check that vtables are built and dispatched through correctly when the
+// principal trait ref of the object carries a lifetime, either in an
+// associated type binding or as a parameter of the trait itself.
+
+trait Name<'a> {
+    fn name(&self) -> &'a str;
+    fn len(&self) -> usize { self.name().len() }
+}
+
+struct Named<'a>(&'a str);
+
+impl<'a> Name<'a> for Named<'a> {
+    fn name(&self) -> &'a str { self.0 }
+}
+
+fn words<'a>(s: &'a str) -> Box<Iterator<Item=&'a str> + 'a> {
+    Box::new(s.split(' '))
+}
+
+fn name_of<'a>(n: &Name<'a>) -> &'a str {
+    n.name()
+}
+
+fn main() {
+    let text = String::from("foo bar baz");
+
+    let mut it = words(&text);
+    assert_eq!(it.next(), Some("foo"));
+    assert_eq!(it.collect::<Vec<_>>(), ["bar", "baz"]);
+
+    let named = Named(&text[4..7]);
+    let obj: &Name = &named;
+    assert_eq!(name_of(obj), "bar");
+    assert_eq!(obj.len(), 3);
+}